@@ -1,101 +1,458 @@
-use primitive_types::U256;
+use primitive_types::{U256, U512};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use std::cmp::{Eq, PartialEq};
+use std::marker::PhantomData;
 use std::ops::{Add, Div, Mul, Sub};
 
-#[derive(Debug, Clone)]
-pub struct FieldElement {
+/// Truncates a `U512` to its low 256 bits, i.e. reduces it mod `R = 2^256`.
+fn low_u256(x: U512) -> U256 {
+    U256([x.0[0], x.0[1], x.0[2], x.0[3]])
+}
+
+/// Selects `a` if `choice` is 0 or `b` if `choice` is 1, branching on neither
+/// (each `u64` limb is chosen via `subtle`'s constant-time primitives).
+fn ct_select_u256(a: U256, b: U256, choice: Choice) -> U256 {
+    let mut out = [0u64; 4];
+    for ((o, x), y) in out.iter_mut().zip(a.0.iter()).zip(b.0.iter()) {
+        *o = u64::conditional_select(x, y, choice);
+    }
+    U256(out)
+}
+
+/// Compares two `U256`s for equality without short-circuiting on the first
+/// differing limb.
+fn ct_eq_u256(a: U256, b: U256) -> Choice {
+    a.0.iter()
+        .zip(b.0.iter())
+        .fold(Choice::from(1u8), |acc, (x, y)| acc & x.ct_eq(y))
+}
+
+/// Multiplies two `U256`s mod `2^256` (`U256`'s own `Mul` panics on overflow
+/// instead of wrapping, so the product is carried through a `U512`).
+fn mul_mod_r(a: U256, b: U256) -> U256 {
+    low_u256(U512::from(a) * U512::from(b))
+}
+
+/// Computes the Montgomery constant `mu = -prime^-1 mod 2^256` via Newton's
+/// method. `prime` must be odd (true for every modulus used here), which
+/// guarantees it is invertible mod `2^256`.
+fn mont_mu(prime: U256) -> U256 {
+    // `prime` itself is correct to 3 bits as a first guess; each iteration
+    // of `x = x * (2 - prime * x)` doubles the number of correct bits.
+    let mut inv = prime;
+    for _ in 0..8 {
+        let t = mul_mod_r(prime, inv);
+        let two_minus_t = U256::from(2u8).overflowing_sub(t).0;
+        inv = mul_mod_r(inv, two_minus_t);
+    }
+    U256::zero().overflowing_sub(inv).0
+}
+
+/// Halves `x` modulo `prime`, i.e. computes `x * 2^-1 mod prime`. If `x` is
+/// odd, `x + prime` is needed before the division, which can exceed
+/// `U256::MAX` for primes close to `2^256` (secp256k1's); the addition is
+/// carried through a `U512` to avoid that overflow, the same trick
+/// `montgomery_reduce` uses below.
+fn half_mod(x: U256, prime: U256) -> U256 {
+    if x % U256::from(2u8) == U256::zero() {
+        x / U256::from(2u8)
+    } else {
+        low_u256((U512::from(x) + U512::from(prime)) >> 1)
+    }
+}
+
+/// Computes `R^2 mod prime` (`R = 2^256`), used to lift a canonical value
+/// into Montgomery form.
+fn mont_r2(prime: U256) -> U256 {
+    let r_mod_prime = (U256::max_value() % prime + U256::one()) % prime;
+    low_u256(U512::from(r_mod_prime) * U512::from(r_mod_prime) % U512::from(prime))
+}
+
+/// Montgomery reduction (REDC): given `t`, returns `t * R^-1 mod prime`.
+fn montgomery_reduce(t: U512, prime: U256, mu: U256) -> U256 {
+    let t_lo = low_u256(t);
+    let m = mul_mod_r(t_lo, mu);
+    // `t + m * prime` can exceed `U512::MAX` for primes close to `2^256`
+    // (secp256k1's), so only the low half is added to `m * prime` (the sum
+    // is divisible by `R`) and the high half of `t` is added after the shift.
+    // `u < 2 * prime` may still exceed `U256::MAX`, so it stays a `U512`.
+    let u = (t >> 256) + ((U512::from(t_lo) + U512::from(m) * U512::from(prime)) >> 256);
+    // The final conditional subtraction operates on secret data during
+    // exponentiation, so select between `diff` and `u` rather than branch.
+    let (diff, u_was_smaller) = u.overflowing_sub(U512::from(prime));
+    ct_select_u256(low_u256(diff), low_u256(u), Choice::from(u_was_smaller as u8))
+}
+
+/// A prime field modulus known at compile time, so that two `FieldElement`s
+/// sharing the same `P` are statically guaranteed to belong to the same field.
+pub trait PrimeFieldParams {
+    fn modulus() -> U256;
+    fn name() -> &'static str;
+}
+
+/// The field modulus used by secp256k1: `2^256 - 2^32 - 977`.
+pub struct Secp256k1Prime;
+
+impl PrimeFieldParams for Secp256k1Prime {
+    fn modulus() -> U256 {
+        U256([
+            0xfffffffefffffc2f,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+        ])
+    }
+
+    fn name() -> &'static str {
+        "secp256k1"
+    }
+}
+
+/// Errors produced by fallible `FieldElement` construction and decoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldError {
+    /// The value is not in the field's range `0..prime`.
+    ModulusOverflow,
+    /// Not enough bytes were supplied to decode a `FieldElement`.
+    ShortRead,
+    /// The operands belong to different prime fields.
+    ///
+    /// Unreachable today: every `FieldElement` is parameterized by a
+    /// compile-time [`PrimeFieldParams`], so two operands always share a
+    /// field. Kept so callers matching on `FieldError` don't need to change
+    /// if a runtime-modulus variant is ever reintroduced.
+    MismatchedFields,
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldError::ModulusOverflow => write!(f, "value is not in the field's range"),
+            FieldError::ShortRead => write!(f, "not enough bytes to decode a field element"),
+            FieldError::MismatchedFields => write!(f, "operands belong to different prime fields"),
+        }
+    }
+}
+
+impl std::error::Error for FieldError {}
+
+pub struct FieldElement<P: PrimeFieldParams> {
+    /// Stored in Montgomery form (`num * R mod prime`) so that `Mul` can
+    /// reduce via REDC instead of a general modular reduction.
     pub num: U256,
-    pub prime: U256,
+    mu: U256,
+    _marker: PhantomData<P>,
+}
+
+impl<P: PrimeFieldParams> Clone for FieldElement<P> {
+    fn clone(&self) -> Self {
+        Self {
+            num: self.num,
+            mu: self.mu,
+            _marker: PhantomData,
+        }
+    }
 }
 
-impl FieldElement {
-    pub fn new(num: U256, prime: U256) -> Self {
+impl<P: PrimeFieldParams> std::fmt::Debug for FieldElement<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FieldElement")
+            .field("num", &self.num)
+            .field("prime", &P::modulus())
+            .finish()
+    }
+}
+
+impl<P: PrimeFieldParams> FieldElement<P> {
+    pub fn new(num: U256) -> Self {
+        Self::try_new(num).unwrap_or_else(|_| {
+            panic!("Num {:?} not in field range 0 to {:?}", num, P::modulus())
+        })
+    }
+
+    /// Fallible version of [`Self::new`]: returns `Err` instead of panicking
+    /// when `num` is not in the field's range.
+    pub fn try_new(num: U256) -> Result<Self, FieldError> {
+        let prime = P::modulus();
+        if num >= prime {
+            Err(FieldError::ModulusOverflow)
+        } else {
+            Ok(Self::from_canonical(num))
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::from_canonical(U256::zero())
+    }
+
+    pub fn one() -> Self {
+        Self::from_canonical(U256::one())
+    }
+
+    /// Serializes the canonical value as 32 big-endian bytes.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        self.to_canonical().to_big_endian(&mut bytes);
+        bytes
+    }
+
+    /// Decodes 32 big-endian bytes into a `FieldElement`, rejecting inputs
+    /// that are too short or that encode a value `>= prime`.
+    pub fn from_be_bytes(bytes: &[u8]) -> Result<Self, FieldError> {
+        if bytes.len() != 32 {
+            return Err(FieldError::ShortRead);
+        }
+        Self::try_new(U256::from_big_endian(bytes))
+    }
+
+    /// Fallible [`Add`]; always succeeds now that the compile-time `P`
+    /// guarantees both operands share a field, kept for parity with
+    /// [`Self::try_new`] and the byte codec.
+    pub fn checked_add(self, other: Self) -> Result<Self, FieldError> {
+        Ok(self + other)
+    }
+
+    /// Fallible [`Sub`]; see [`Self::checked_add`].
+    pub fn checked_sub(self, other: Self) -> Result<Self, FieldError> {
+        Ok(self - other)
+    }
+
+    /// Fallible [`Mul`]; see [`Self::checked_add`].
+    pub fn checked_mul(self, other: Self) -> Result<Self, FieldError> {
+        Ok(self * other)
+    }
+
+    /// Constant-time equality: compares all 256 bits unconditionally, unlike
+    /// [`PartialEq`], which may short-circuit. Use this wherever `self` or
+    /// `other` may hold a secret (a private key, a nonce, ...).
+    pub fn ct_eq(&self, other: &Self) -> Choice {
+        ct_eq_u256(self.num, other.num)
+    }
+
+    /// Constant-time zero test.
+    pub fn is_zero(&self) -> Choice {
+        ct_eq_u256(self.num, U256::zero())
+    }
+
+    /// Selects `a` if `choice` is 0 or `b` if `choice` is 1, without
+    /// branching on `choice`.
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            num: ct_select_u256(a.num, b.num, choice),
+            mu: a.mu,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Lifts an ordinary integer into Montgomery form.
+    fn from_canonical(num: U256) -> Self {
+        let prime = P::modulus();
+        let mu = mont_mu(prime);
+        let r2 = mont_r2(prime);
+        let mont_num = montgomery_reduce(U512::from(num) * U512::from(r2), prime, mu);
+        Self {
+            num: mont_num,
+            mu,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Builds a `FieldElement` from a value already in Montgomery form.
+    fn from_montgomery(num: U256, mu: U256) -> Self {
+        let prime = P::modulus();
         if num >= prime {
             panic!("Num {:?} not in field range 0 to {:?}", num, prime)
         }
-        Self { num, prime }
+        Self {
+            num,
+            mu,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Converts this element back out of Montgomery form into an ordinary integer.
+    pub fn to_canonical(&self) -> U256 {
+        montgomery_reduce(U512::from(self.num), P::modulus(), self.mu)
+    }
+
+    /// Computes the multiplicative inverse via the binary extended Euclidean
+    /// algorithm, `None` if `self` is not invertible (i.e. `self` is zero).
+    /// Much faster than `self.pow(prime - 2)` since it avoids ~256 squarings,
+    /// but its iteration count and branches depend on `self`'s bit pattern,
+    /// so it is **not constant-time**. Only call this on public values; `Div`
+    /// deliberately does not use it for exactly this reason.
+    pub fn inverse(&self) -> Option<Self> {
+        let prime = P::modulus();
+        let a = self.to_canonical();
+        if a == U256::zero() {
+            return None;
+        }
+
+        let mut u = a;
+        let mut v = prime;
+        let mut x1 = U256::one();
+        let mut x2 = U256::zero();
+
+        while u != U256::one() && v != U256::one() {
+            if u == U256::zero() || v == U256::zero() {
+                return None;
+            }
+            while u % U256::from(2u8) == U256::zero() {
+                u /= U256::from(2u8);
+                x1 = half_mod(x1, prime);
+            }
+            while v % U256::from(2u8) == U256::zero() {
+                v /= U256::from(2u8);
+                x2 = half_mod(x2, prime);
+            }
+            if u >= v {
+                u -= v;
+                x1 = if x1 >= x2 {
+                    x1 - x2
+                } else {
+                    prime - x2 + x1
+                };
+            } else {
+                v -= u;
+                x2 = if x2 >= x1 {
+                    x2 - x1
+                } else {
+                    prime - x1 + x2
+                };
+            }
+        }
+
+        let inv = if u == U256::one() { x1 } else { x2 } % prime;
+        Some(Self::new(inv))
+    }
+
+    /// Returns a square root of `self` (`y` such that `y * y == self`), or
+    /// `None` if `self` is not a quadratic residue mod the field's prime.
+    pub fn sqrt(&self) -> Option<Self> {
+        let prime = P::modulus();
+        if prime % U256::from(4u8) == U256::from(3u8) {
+            let y = self.clone().pow((prime + U256::from(1u8)) / U256::from(4u8));
+            if y.clone() * y.clone() == self.clone() {
+                Some(y)
+            } else {
+                None
+            }
+        } else {
+            self.tonelli_shanks(prime)
+        }
+    }
+
+    /// General-case modular square root via Tonelli-Shanks.
+    fn tonelli_shanks(&self, prime: U256) -> Option<Self> {
+        let mut q = prime - 1;
+        let mut s = 0u32;
+        while q % U256::from(2u8) == U256::zero() {
+            q /= U256::from(2u8);
+            s += 1;
+        }
+
+        // Find a quadratic non-residue `z` via the Euler criterion.
+        let minus_one = Self::new(prime - 1);
+        let mut candidate = U256::from(2u8);
+        let z = loop {
+            let z = Self::new(candidate);
+            if z.clone().pow((prime - U256::from(1u8)) / U256::from(2u8)) == minus_one {
+                break z;
+            }
+            candidate += U256::one();
+        };
+
+        let mut m = s;
+        let mut c = z.pow(q);
+        let mut t = self.clone().pow(q);
+        let mut r = self.clone().pow((q + U256::from(1u8)) / U256::from(2u8));
+
+        loop {
+            if t == Self::one() {
+                return Some(r);
+            }
+
+            let mut i = 1u32;
+            let mut temp = t.clone() * t.clone();
+            while temp != Self::one() {
+                if i + 1 >= m {
+                    return None;
+                }
+                temp = temp.clone() * temp.clone();
+                i += 1;
+            }
+
+            let b = c.pow(U256::one() << (m - i - 1) as usize);
+            m = i;
+            c = b.clone() * b.clone();
+            t = t * c.clone();
+            r = r * b;
+        }
     }
 }
 
-impl PartialEq for FieldElement {
+impl<P: PrimeFieldParams> PartialEq for FieldElement<P> {
     fn eq(&self, other: &Self) -> bool {
-        self.prime == other.prime && self.num == other.num
+        self.num == other.num
     }
 }
 
-impl Eq for FieldElement {}
+impl<P: PrimeFieldParams> Eq for FieldElement<P> {}
 
-impl Add for FieldElement {
+impl<P: PrimeFieldParams> Add for FieldElement<P> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self::Output {
-        if self.prime != other.prime {
-            panic!("Prime number should be same")
-        }
-        if self.num + other.num >= self.prime {
-            Self {
-                num: self.num + other.num - self.prime,
-                prime: self.prime,
-            }
+        let prime = P::modulus();
+        // `self.num + other.num` can exceed `U256::MAX` for primes close to
+        // `2^256` (secp256k1's), so the sum is carried through a `U512`
+        // before reducing mod `prime`, same as `Mul`'s `montgomery_reduce`.
+        let sum = U512::from(self.num) + U512::from(other.num);
+        let sum = if sum >= U512::from(prime) {
+            sum - U512::from(prime)
         } else {
-            Self {
-                num: self.num + other.num,
-                prime: self.prime,
-            }
-        }
+            sum
+        };
+        Self::from_montgomery(low_u256(sum), self.mu)
     }
 }
 
-impl Sub for FieldElement {
+impl<P: PrimeFieldParams> Sub for FieldElement<P> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self::Output {
-        if self.prime != other.prime {
-            panic!("Cannot subtract two numbers in different Fields.");
-        }
+        let prime = P::modulus();
         if self.num < other.num {
-            Self::new(self.prime - self.num + other.num, self.prime)
+            Self::from_montgomery(prime - other.num + self.num, self.mu)
         } else {
-            Self::new(self.num - other.num, self.prime)
+            Self::from_montgomery(self.num - other.num, self.mu)
         }
     }
 }
 
-impl Mul for FieldElement {
+impl<P: PrimeFieldParams> Mul for FieldElement<P> {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
-        if self.prime != other.prime {
-            panic!("Cannot multiply two numbers in different Fields.");
-        }
-        let mut ret = FieldElement::new(U256::from(0), self.prime);
-        let mut counter = other.num;
-        loop {
-            if counter < U256::from(u128::MAX) {
-                for _ in 0..counter.as_u128() {
-                    ret = ret + self.clone();
-                }
-                break;
-            }
-
-            if counter >= U256::from(u128::MAX) {
-                for _ in 0..u128::MAX {
-                    ret = ret + self.clone();
-                }
-            }
-            counter -= U256::from(u128::MAX);
-        }
-        ret
+        let prime = P::modulus();
+        // Montgomery multiplication: both operands are already `* R mod prime`,
+        // so a single REDC on their product reduces back to `a * b * R mod prime`.
+        let product = U512::from(self.num) * U512::from(other.num);
+        let num = montgomery_reduce(product, prime, self.mu);
+        Self::from_montgomery(num, self.mu)
     }
 }
 
-impl Div for FieldElement {
+impl<P: PrimeFieldParams> Div for FieldElement<P> {
     type Output = Self;
 
     fn div(self, other: Self) -> Self {
-        let p = self.prime;
-        self * other.pow(p - 2)
+        // `other` may be secret (an ECDSA nonce or private key), so this
+        // goes through the constant-time square-and-multiply exponentiation
+        // from `Pow` rather than the variable-time `inverse`.
+        let prime = P::modulus();
+        self * other.pow(prime - 2)
     }
 }
 
@@ -106,33 +463,32 @@ where
     fn pow(self, exponent: T) -> Self;
 }
 
-impl Pow<U256> for FieldElement {
+impl<P: PrimeFieldParams> Pow<U256> for FieldElement<P> {
     fn pow(self, exponent: U256) -> Self {
-        let mut ret = FieldElement::new(U256::from(1), self.prime);
-        let mut counter = exponent % (self.prime - 1);
-
-        loop {
-            if counter < U256::from(u128::MAX) {
-                for _ in 0..counter.as_u128() {
-                    ret = ret * self.clone();
-                }
-                break;
-            }
-            if counter >= U256::from(u128::MAX) {
-                for _ in 0..u128::MAX {
-                    ret = ret * self.clone();
-                }
-            }
-            counter -= U256::from(u128::MAX);
+        let prime = P::modulus();
+        let exponent = exponent % (prime - 1);
+
+        // Square-and-multiply: walk the exponent's bits from MSB to LSB,
+        // squaring the accumulator and multiplying by `self` whenever a bit is
+        // set. The bit may be a secret (e.g. an ECDSA nonce), so the choice
+        // between `squared` and `squared * self` is a constant-time select
+        // rather than a branch.
+        let mut acc = FieldElement::<P>::one();
+        for i in (0..256).rev() {
+            let squared = acc.clone() * acc.clone();
+            let multiplied = squared.clone() * self.clone();
+            let bit = Choice::from(exponent.bit(i) as u8);
+            acc = FieldElement::conditional_select(&squared, &multiplied, bit);
         }
-        ret
+        acc
     }
 }
 
-impl Pow<i32> for FieldElement {
+impl<P: PrimeFieldParams> Pow<i32> for FieldElement<P> {
     fn pow(self, exponent: i32) -> Self {
+        let prime = P::modulus();
         let n = if exponent < 0 {
-            self.prime - 1 - U256::from(-exponent)
+            prime - 1 - U256::from(-exponent)
         } else {
             U256::from(exponent)
         };
@@ -144,16 +500,56 @@ impl Pow<i32> for FieldElement {
 mod tests {
     use super::*;
 
+    struct Mod3;
+    impl PrimeFieldParams for Mod3 {
+        fn modulus() -> U256 {
+            U256::from(3)
+        }
+        fn name() -> &'static str {
+            "mod3"
+        }
+    }
+
+    struct Mod7;
+    impl PrimeFieldParams for Mod7 {
+        fn modulus() -> U256 {
+            U256::from(7)
+        }
+        fn name() -> &'static str {
+            "mod7"
+        }
+    }
+
+    struct Mod13;
+    impl PrimeFieldParams for Mod13 {
+        fn modulus() -> U256 {
+            U256::from(13)
+        }
+        fn name() -> &'static str {
+            "mod13"
+        }
+    }
+
+    struct Mod19;
+    impl PrimeFieldParams for Mod19 {
+        fn modulus() -> U256 {
+            U256::from(19)
+        }
+        fn name() -> &'static str {
+            "mod19"
+        }
+    }
+
     #[test]
     fn new() {
-        FieldElement::new(U256::from(2), U256::from(3));
+        FieldElement::<Mod3>::new(U256::from(2));
     }
 
     #[test]
     fn eq() {
-        let a = FieldElement::new(U256::from(2), U256::from(3));
-        let b = FieldElement::new(U256::from(2), U256::from(3));
-        let c = FieldElement::new(U256::from(1), U256::from(3));
+        let a = FieldElement::<Mod3>::new(U256::from(2));
+        let b = FieldElement::<Mod3>::new(U256::from(2));
+        let c = FieldElement::<Mod3>::new(U256::from(1));
 
         assert_eq!(a, b);
         assert_ne!(a, c);
@@ -161,50 +557,194 @@ mod tests {
 
     #[test]
     fn add() {
-        let a = FieldElement::new(U256::from(2), U256::from(7));
-        let b = FieldElement::new(U256::from(1), U256::from(7));
-        let c = FieldElement::new(U256::from(3), U256::from(7));
+        let a = FieldElement::<Mod7>::new(U256::from(2));
+        let b = FieldElement::<Mod7>::new(U256::from(1));
+        let c = FieldElement::<Mod7>::new(U256::from(3));
 
         assert_eq!(a + b, c);
     }
 
     #[test]
     fn sub() {
-        let a = FieldElement::new(U256::from(6), U256::from(7));
-        let b = FieldElement::new(U256::from(4), U256::from(7));
-        let c = FieldElement::new(U256::from(2), U256::from(7));
+        let a = FieldElement::<Mod7>::new(U256::from(6));
+        let b = FieldElement::<Mod7>::new(U256::from(4));
+        let c = FieldElement::<Mod7>::new(U256::from(2));
 
         assert_eq!(a - b, c);
     }
 
     #[test]
     fn mul() {
-        let a = FieldElement::new(U256::from(3), U256::from(13));
-        let b = FieldElement::new(U256::from(12), U256::from(13));
-        let c = FieldElement::new(U256::from(10), U256::from(13));
+        let a = FieldElement::<Mod13>::new(U256::from(3));
+        let b = FieldElement::<Mod13>::new(U256::from(12));
+        let c = FieldElement::<Mod13>::new(U256::from(10));
 
         assert_eq!(a * b, c);
     }
 
     #[test]
     fn pow() {
-        let a = FieldElement::new(U256::from(3), U256::from(13));
-        let b = FieldElement::new(U256::from(1), U256::from(13));
+        let a = FieldElement::<Mod13>::new(U256::from(3));
+        let b = FieldElement::<Mod13>::new(U256::from(1));
 
         assert_eq!(a.pow(U256::from(3)), b);
 
-        let c = FieldElement::new(U256::from(7), U256::from(13));
-        let d = FieldElement::new(U256::from(8), U256::from(13));
+        let c = FieldElement::<Mod13>::new(U256::from(7));
+        let d = FieldElement::<Mod13>::new(U256::from(8));
 
         assert_eq!(c.pow(-3), d);
     }
 
     #[test]
     fn div() {
-        let a = FieldElement::new(U256::from(7), U256::from(19));
-        let b = FieldElement::new(U256::from(5), U256::from(19));
-        let c = FieldElement::new(U256::from(9), U256::from(19));
+        let a = FieldElement::<Mod19>::new(U256::from(7));
+        let b = FieldElement::<Mod19>::new(U256::from(5));
+        let c = FieldElement::<Mod19>::new(U256::from(9));
 
         assert_eq!(a / b, c);
     }
+
+    #[test]
+    fn sqrt_shortcut_for_prime_congruent_to_3_mod_4() {
+        let a = FieldElement::<Mod19>::new(U256::from(4));
+        let y = a.sqrt().expect("4 is a QR mod 19");
+
+        assert_eq!(y.clone() * y, a);
+    }
+
+    #[test]
+    fn sqrt_tonelli_shanks_for_prime_congruent_to_1_mod_4() {
+        let a = FieldElement::<Mod13>::new(U256::from(4));
+        let y = a.sqrt().expect("4 is a QR mod 13");
+
+        assert_eq!(y.clone() * y, a);
+    }
+
+    #[test]
+    fn sqrt_non_residue_returns_none() {
+        let a = FieldElement::<Mod19>::new(U256::from(2));
+
+        assert_eq!(a.sqrt(), None);
+    }
+
+    #[test]
+    fn try_new_rejects_out_of_range() {
+        assert_eq!(
+            FieldElement::<Mod7>::try_new(U256::from(7)),
+            Err(FieldError::ModulusOverflow)
+        );
+        assert!(FieldElement::<Mod7>::try_new(U256::from(6)).is_ok());
+    }
+
+    #[test]
+    fn be_bytes_round_trip() {
+        let a = FieldElement::<Secp256k1Prime>::new(U256::from(12345));
+        let bytes = a.to_be_bytes();
+        let b = FieldElement::<Secp256k1Prime>::from_be_bytes(&bytes).unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn from_be_bytes_rejects_short_reads() {
+        assert_eq!(
+            FieldElement::<Secp256k1Prime>::from_be_bytes(&[0u8; 31]),
+            Err(FieldError::ShortRead)
+        );
+    }
+
+    #[test]
+    fn from_be_bytes_rejects_values_at_or_above_the_prime() {
+        let mut bytes = [0u8; 32];
+        Secp256k1Prime::modulus().to_big_endian(&mut bytes);
+        assert_eq!(
+            FieldElement::<Secp256k1Prime>::from_be_bytes(&bytes),
+            Err(FieldError::ModulusOverflow)
+        );
+    }
+
+    #[test]
+    fn checked_ops() {
+        let a = FieldElement::<Mod7>::new(U256::from(5));
+        let b = FieldElement::<Mod7>::new(U256::from(3));
+
+        assert_eq!(a.clone().checked_add(b.clone()).unwrap(), a.clone() + b.clone());
+        assert_eq!(a.clone().checked_sub(b.clone()).unwrap(), a.clone() - b.clone());
+        assert_eq!(a.clone().checked_mul(b.clone()).unwrap(), a * b);
+    }
+
+    #[test]
+    fn ct_eq() {
+        let a = FieldElement::<Mod7>::new(U256::from(3));
+        let b = FieldElement::<Mod7>::new(U256::from(3));
+        let c = FieldElement::<Mod7>::new(U256::from(4));
+
+        assert_eq!(a.ct_eq(&b).unwrap_u8(), 1);
+        assert_eq!(a.ct_eq(&c).unwrap_u8(), 0);
+    }
+
+    #[test]
+    fn is_zero() {
+        let zero = FieldElement::<Mod7>::zero();
+        let nonzero = FieldElement::<Mod7>::new(U256::from(1));
+
+        assert_eq!(zero.is_zero().unwrap_u8(), 1);
+        assert_eq!(nonzero.is_zero().unwrap_u8(), 0);
+    }
+
+    #[test]
+    fn conditional_select() {
+        let a = FieldElement::<Mod7>::new(U256::from(2));
+        let b = FieldElement::<Mod7>::new(U256::from(5));
+
+        assert_eq!(
+            FieldElement::conditional_select(&a, &b, Choice::from(0)),
+            a
+        );
+        assert_eq!(
+            FieldElement::conditional_select(&a, &b, Choice::from(1)),
+            b
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let a = FieldElement::<Mod19>::new(U256::from(7));
+        let inv = a.inverse().expect("7 is invertible mod 19");
+
+        assert_eq!(a * inv, FieldElement::<Mod19>::one());
+    }
+
+    #[test]
+    fn inverse_of_zero_is_none() {
+        let zero = FieldElement::<Mod19>::zero();
+
+        assert_eq!(zero.inverse(), None);
+    }
+
+    #[test]
+    fn inverse_at_secp256k1_scale() {
+        let prime = Secp256k1Prime::modulus();
+        for num in [
+            prime - U256::one(),
+            prime - U256::from(2u8),
+            U256::from(2u8).pow(U256::from(255u8)),
+            U256::from(0x1234_5678_9abc_def0u64),
+        ] {
+            let a = FieldElement::<Secp256k1Prime>::new(num);
+            let inv = a.clone().inverse().expect("nonzero value is invertible");
+
+            assert_eq!(a * inv, FieldElement::<Secp256k1Prime>::one());
+        }
+    }
+
+    #[test]
+    fn checked_add_does_not_overflow_at_secp256k1_scale() {
+        let prime = Secp256k1Prime::modulus();
+        let a = FieldElement::<Secp256k1Prime>::new(prime - U256::from(2u8));
+        let b = FieldElement::<Secp256k1Prime>::new(prime - U256::from(3u8));
+
+        let sum = a.checked_add(b).expect("Add must not overflow U256");
+        assert_eq!(sum.to_canonical(), prime - U256::from(5u8));
+    }
 }